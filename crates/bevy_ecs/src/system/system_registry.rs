@@ -1,10 +1,14 @@
 use bevy_utils::tracing::warn;
 use bevy_utils::HashMap;
+use std::any::{Any, TypeId};
+use std::collections::VecDeque;
 use std::hash::Hash;
 use std::marker::PhantomData;
 
 use crate::schedule::{IntoSystemDescriptor, SystemLabel};
-use crate::system::{Command, IntoSystem, System, SystemTypeIdLabel};
+use crate::system::{
+    Command, Commands, ExclusiveSystem, IntoExclusiveSystem, IntoSystem, System, SystemTypeIdLabel,
+};
 use crate::world::{Mut, World};
 // Needed for derive(Component) macro
 use crate as bevy_ecs;
@@ -24,9 +28,19 @@ use bevy_ecs_macros::Component;
 ///
 /// # Limitations
 ///
-///  - stored systems cannot be chained: they can neither have an [`In`](crate::system::In) nor return any values
-///  - stored systems cannot recurse: they cannot run other systems via the [`SystemRegistry`] methods on `World` or `Commands`
-///  - exclusive systems cannot be used
+///  - [`run_system`](SystemRegistry::run_system) itself is still limited to `In = ()` / `Out = ()`;
+///    use [`run_system_with_input`](SystemRegistry::run_system_with_input) for systems that take an input or return a value
+///  - a system can recurse into running other systems (e.g. via `commands.run_system`, or an exclusive
+///    system calling `world.run_system` directly), but only up to [`MAX_RUN_RECURSION_DEPTH`] deep;
+///    beyond that, [`SystemRegistryError::RecursionLimitExceeded`] is raised
+///  - [`run_system_with_input`](crate::world::World::run_system_with_input) is the one exception to the
+///    above: since it must hand its `Out` value back to the caller synchronously, it cannot be deferred,
+///    so calling it from inside an already-running system panics instead of recursing cleanly
+///  - a system registered via `run_system_with_input` is discoverable and removable under its
+///    [`SystemTypeIdLabel`] just like any other system, but it can't be *run* that way: a label-based
+///    run (e.g. [`run_systems_by_label`](SystemRegistry::run_systems_by_label)) panics if it resolves to
+///    one, since only the original `run_system_with_input` caller has the concrete `In`/`Out` type
+///    needed to run it
 ///
 /// # Examples
 ///
@@ -77,17 +91,181 @@ use bevy_ecs_macros::Component;
 /// world.run_system(spawn_7_entities);
 /// world.run_system(assert_7_spawned);
 /// ```
+///
+/// Systems that need an input value or return an output can be run (and have their state cached)
+/// via [`run_system_with_input`](World::run_system_with_input):
+///
+/// ```rust
+/// use bevy_ecs::prelude::*;
+///
+/// let mut world = World::new();
+///
+/// #[derive(Default, PartialEq, Debug)]
+/// struct Counter(u8);
+///
+/// fn add_to_counter(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+///     counter.0 += amount;
+///     counter.0
+/// }
+///
+/// world.init_resource::<Counter>();
+/// let total = world.run_system_with_input(add_to_counter, 3);
+/// assert_eq!(total, 3);
+/// ```
 #[derive(Default)]
 pub struct SystemRegistry {
-    systems: Vec<StoredSystem>,
-    // Stores the index of all systems that match the key's label
-    labels: HashMap<Box<dyn SystemLabel>, Vec<usize>>,
+    systems: SlotMap<StoredSystem>,
+    // Exclusive systems (`&mut World` systems) are stored separately, since they require a different trait
+    // (`ExclusiveSystem`) than ordinary `System<In = (), Out = ()>`s, but share the same `labels` lookup table.
+    exclusive_systems: SlotMap<ExclusiveStoredSystem>,
+    // Systems with a non-unit `In` and/or `Out` are stored separately too, since they require a
+    // different trait (`System<In = I, Out = O>`) than `StoredSystem`'s `()`-in/`()`-out systems, but
+    // share the same `labels` lookup table, the same way `exclusive_systems` does.
+    io_systems: SlotMap<IoStoredSystem>,
+    // Maps each `run_system_with_input`-registered system's input type, output type and label to the
+    // `SystemId` it's stored under in `io_systems`, so a repeat call can find the cached instance
+    // without scanning `labels`.
+    io_system_keys: HashMap<IoSystemKey, SystemId>,
+    // Stores the `SystemId` of all systems (of any storage) that match the key's label
+    labels: HashMap<Box<dyn SystemLabel>, Vec<SystemId>>,
+}
+
+/// A stable handle to a system stored in a [`SystemRegistry`], returned by the registration methods.
+///
+/// Unlike a raw index into the registry's internal storage, a [`SystemId`] remains distinguishable from a
+/// reused slot across calls to [`unregister_system`](SystemRegistry::unregister_system):
+/// each slot's generation is bumped whenever it is vacated, so a stale `SystemId` can be detected instead of
+/// silently resolving to whatever system was later registered in its place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemId {
+    index: usize,
+    generation: u32,
+    storage: SystemStorage,
+}
+
+/// Which of [`SystemRegistry`]'s storages a [`SystemId`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum SystemStorage {
+    Parallel,
+    Exclusive,
+    Io,
+}
+
+/// Returns the first id in `ids` that [`run_system_at`](SystemRegistry::run_system_at) can actually
+/// dispatch, i.e. the first one that isn't [`SystemStorage::Io`] (an `Io` system, from
+/// `run_system_with_input`, can share a label with a runnable system but can't be run through it).
+fn first_runnable_id(ids: &[SystemId]) -> Option<SystemId> {
+    ids.iter()
+        .find(|system_id| system_id.storage != SystemStorage::Io)
+        .copied()
 }
 
 struct StoredSystem {
     system: Box<dyn System<In = (), Out = ()>>,
 }
 
+/// A system registered via [`SystemRegistry::run_system_with_input`], type-erased behind `Any` since
+/// its concrete `In`/`Out` types aren't known until a caller who does know them downcasts it back.
+struct IoStoredSystem {
+    system: Box<dyn Any + Send + Sync>,
+}
+
+struct ExclusiveStoredSystem {
+    system: Box<dyn ExclusiveSystem>,
+}
+
+/// A generational slot map: `free_indices` tracks vacated slots so their index can be reused,
+/// while each slot's generation is bumped on removal so stale handles are detected rather than silently aliased.
+struct SlotMap<T> {
+    slots: Vec<Slot<T>>,
+    free_indices: Vec<usize>,
+}
+
+impl<T> Default for SlotMap<T> {
+    fn default() -> Self {
+        SlotMap {
+            slots: Vec::new(),
+            free_indices: Vec::new(),
+        }
+    }
+}
+
+enum Slot<T> {
+    Occupied { value: T, generation: u32 },
+    Vacant { generation: u32 },
+}
+
+impl<T> SlotMap<T> {
+    /// Inserts `value`, reusing a vacated slot (and its bumped generation) if one is available.
+    fn insert(&mut self, value: T) -> (usize, u32) {
+        if let Some(index) = self.free_indices.pop() {
+            let generation = match self.slots[index] {
+                Slot::Vacant { generation } => generation,
+                Slot::Occupied { .. } => unreachable!("a free index pointed at an occupied slot"),
+            };
+            self.slots[index] = Slot::Occupied { value, generation };
+            (index, generation)
+        } else {
+            let index = self.slots.len();
+            self.slots.push(Slot::Occupied {
+                value,
+                generation: 0,
+            });
+            (index, 0)
+        }
+    }
+
+    /// Returns the value at `index`, if `generation` still matches (i.e. it hasn't been removed since).
+    fn get_mut(&mut self, index: usize, generation: u32) -> Option<&mut T> {
+        match self.slots.get_mut(index)? {
+            Slot::Occupied {
+                value,
+                generation: slot_generation,
+            } if *slot_generation == generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Vacates the slot at `index` (bumping its generation) if `generation` still matches.
+    /// Returns whether a slot was actually removed.
+    fn remove(&mut self, index: usize, generation: u32) -> bool {
+        match self.slots.get(index) {
+            Some(Slot::Occupied {
+                generation: slot_generation,
+                ..
+            }) if *slot_generation == generation => {
+                self.slots[index] = Slot::Vacant {
+                    generation: slot_generation.wrapping_add(1),
+                };
+                self.free_indices.push(index);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A type-erased key used to store and look up systems registered via [`SystemRegistry::run_system_with_input`].
+///
+/// Systems are distinguished by their input type, output type and [`SystemLabel`]
+/// (ordinarily their [`SystemTypeIdLabel`]), mirroring the scheme [`SystemRegistry::run_system`] uses for `()`-in/`()`-out systems.
+#[derive(PartialEq, Eq, Hash)]
+struct IoSystemKey {
+    input_type: TypeId,
+    output_type: TypeId,
+    label: Box<dyn SystemLabel>,
+}
+
+impl IoSystemKey {
+    fn new<I: 'static, O: 'static>(label: Box<dyn SystemLabel>) -> Self {
+        IoSystemKey {
+            input_type: TypeId::of::<I>(),
+            output_type: TypeId::of::<O>(),
+            label,
+        }
+    }
+}
+
 impl SystemRegistry {
     /// Registers a system in the [`SystemRegistry`], so then it can be later run.
     ///
@@ -103,33 +281,42 @@ impl SystemRegistry {
     /// all registered systems that match that label will be evaluated (in insertion order).
     ///
     /// To provide explicit label(s), use [`register_system_with_labels`](SystemRegistry::register_system_with_labels).
+    ///
+    /// Returns the [`SystemId`] of the registered system, which can be passed to
+    /// [`unregister_system`](SystemRegistry::unregister_system) to remove it again
+    /// (for instance, a callback tied to the lifetime of a despawned entity).
     #[inline]
     pub fn register_system<Params, S: IntoSystem<(), (), Params> + 'static>(
         &mut self,
         world: &mut World,
         system: S,
-    ) {
+    ) -> SystemId {
         let automatic_system_label: SystemTypeIdLabel<S> = SystemTypeIdLabel::new();
 
         // This avoids nasty surprising behavior in case systems are registered twice
-        if !self.is_label_registered(automatic_system_label) {
+        if let Some(system_id) = self.first_runnable_system_id(automatic_system_label) {
+            let type_name = std::any::type_name::<S>();
+            warn!("A system of type {type_name} was registered more than once!");
+            system_id
+        } else {
+            self.reclaim_label_from_io(automatic_system_label);
             let boxed_system: Box<dyn System<In = (), Out = ()>> =
                 Box::new(IntoSystem::into_system(system));
             self.register_boxed_system_with_labels(
                 world,
                 boxed_system,
                 vec![Box::new(automatic_system_label)],
-            );
-        } else {
-            let type_name = std::any::type_name::<S>();
-            warn!("A system of type {type_name} was registered more than once!");
-        };
+            )
+        }
     }
 
     /// Register system a system with any number of [`SystemLabel`]s.
     ///
     /// This allows the system to be run whenever any of its labels are run using [`run_systems_by_label`](SystemRegistry::run_systems_by_label).
     ///
+    /// Returns the [`SystemId`] of the registered system, which can be passed to
+    /// [`unregister_system`](SystemRegistry::unregister_system) to remove it again.
+    ///
     /// # Warning
     ///
     /// Unlike the `register_system` method, duplicate systems may be added;
@@ -144,7 +331,7 @@ impl SystemRegistry {
         world: &mut World,
         system: S,
         labels: LI,
-    ) {
+    ) -> SystemId {
         let boxed_system: Box<dyn System<In = (), Out = ()>> =
             Box::new(IntoSystem::into_system(system));
 
@@ -156,13 +343,13 @@ impl SystemRegistry {
             })
             .collect();
 
-        self.register_boxed_system_with_labels(world, boxed_system, collected_labels);
+        self.register_boxed_system_with_labels(world, boxed_system, collected_labels)
     }
 
     /// A more exacting version of [`register_system_with_labels`](Self::register_system_with_labels).
     ///
-    /// Returns the index in the vector of systems that this new system is stored at.
-    /// This is only useful for debugging as an external user of this method.
+    /// Returns the [`SystemId`] that this new system is stored at,
+    /// which can later be used to [`unregister_system`](SystemRegistry::unregister_system) it.
     ///
     /// This can be useful when you have a boxed system or boxed labels,
     /// as the corresponding traits are not implemented for boxed trait objects
@@ -172,58 +359,205 @@ impl SystemRegistry {
         world: &mut World,
         mut boxed_system: Box<dyn System<In = (), Out = ()>>,
         labels: Vec<Box<dyn SystemLabel>>,
-    ) -> usize {
+    ) -> SystemId {
         // Intialize the system's state
         boxed_system.initialize(world);
 
-        let stored_system = StoredSystem {
+        let (index, generation) = self.systems.insert(StoredSystem {
             system: boxed_system,
+        });
+        let system_id = SystemId {
+            index,
+            generation,
+            storage: SystemStorage::Parallel,
         };
+        self.insert_labels(system_id, labels);
 
-        // Add the system to the end of the vec
-        let system_index = self.systems.len();
-        self.systems.push(stored_system);
+        system_id
+    }
 
-        // For each label that the system has
+    /// Records `system_id` in the lookup hashmap under each of `labels`.
+    fn insert_labels(&mut self, system_id: SystemId, labels: Vec<Box<dyn SystemLabel>>) {
         for label in labels {
-            let maybe_label_indexes = self.labels.get_mut(&label);
-
-            // Add the index of the system in the vec to the lookup hashmap
-            // under the corresponding label key
-            if let Some(label_indexes) = maybe_label_indexes {
-                label_indexes.push(system_index);
-            } else {
-                self.labels.insert(label, vec![system_index]);
-            };
+            self.labels.entry(label).or_default().push(system_id);
         }
-
-        system_index
     }
 
-    /// Runs the system at the supplied `index` a single time.
+    /// Runs the system identified by `system_id` a single time, dispatching to whichever
+    /// storage (parallel or exclusive) it was registered in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `system_id` refers to an [`SystemStorage::Io`] system (one registered via
+    /// [`run_system_with_input`](Self::run_system_with_input)): unlike the other two storages, those
+    /// require a concrete `In`/`Out` type that only the original `run_system_with_input` caller has,
+    /// so they can't be dispatched generically here (e.g. via [`run_systems_by_label`](Self::run_systems_by_label)).
     #[inline]
-    fn run_system_at_index(&mut self, world: &mut World, index: usize) {
-        let stored_system = &mut self.systems[index];
+    fn run_system_at(&mut self, world: &mut World, system_id: SystemId) {
+        match system_id.storage {
+            SystemStorage::Parallel => {
+                let stored_system = self
+                    .systems
+                    .get_mut(system_id.index, system_id.generation)
+                    .expect(
+                    "SystemId was not found in the SystemRegistry; this is a bug in SystemRegistry",
+                );
 
-        // Run the system
-        stored_system.system.run((), world);
-        // Apply any generated commands
-        stored_system.system.apply_buffers(world);
+                // Run the system
+                stored_system.system.run((), world);
+                // Apply any generated commands
+                stored_system.system.apply_buffers(world);
+            }
+            SystemStorage::Exclusive => {
+                let stored_system = self
+                    .exclusive_systems
+                    .get_mut(system_id.index, system_id.generation)
+                    .expect(
+                        "SystemId was not found in the SystemRegistry; this is a bug in SystemRegistry",
+                    );
+
+                stored_system.system.run(world);
+            }
+            SystemStorage::Io => {
+                panic!(
+                    "cannot run an Io-registered system (one registered via `run_system_with_input`) \
+                     through `run_systems_by_label`/`run_callback`: it requires a concrete `In`/`Out` \
+                     type that only `run_system_with_input`'s caller has; call that again instead"
+                );
+            }
+        }
     }
 
     /// Is at least one system in the [`SystemRegistry`] associated with the provided [`SystemLabel`]?
+    ///
+    /// This doesn't guarantee the label can be *run*: if it resolves only to a system registered via
+    /// [`run_system_with_input`](Self::run_system_with_input), [`run_systems_by_label`](Self::run_systems_by_label)
+    /// will still panic on it (see the module-level `# Limitations`).
     #[inline]
     pub fn is_label_registered<L: SystemLabel>(&self, label: L) -> bool {
         let boxed_label: Box<dyn SystemLabel> = Box::new(label);
         self.labels.get(&boxed_label).is_some()
     }
 
-    /// Returns the first matching index for systems with this label if any.
+    /// Returns the first matching [`SystemId`] of a runnable-by-label storage (i.e. not
+    /// [`SystemStorage::Io`]) for this label, if any.
+    ///
+    /// `run_system`/`register_system`/`run_exclusive_system`/`run_boxed_system`/`run_boxed_exclusive_system`
+    /// use this to check whether *their own* automatic-label copy already exists: an `Io` system (from
+    /// `run_system_with_input`) can share the same [`SystemTypeIdLabel`] (e.g. a `fn(): In = Out = ()`
+    /// satisfies both APIs' bounds), but it can't be dispatched through
+    /// [`run_system_at`](Self::run_system_at), so it must never be mistaken for one — even if it happens
+    /// to be registered before the runnable copy and so isn't the first entry under the label.
     #[inline]
-    fn first_registered_index<L: SystemLabel>(&self, label: L) -> Option<usize> {
+    fn first_runnable_system_id<L: SystemLabel>(&self, label: L) -> Option<SystemId> {
+        let boxed_label: Box<dyn SystemLabel> = Box::new(label);
+        first_runnable_id(self.labels.get(&boxed_label)?)
+    }
+
+    /// Clears `label`'s entry in `labels` if every [`SystemId`] under it is [`SystemStorage::Io`].
+    ///
+    /// `run_system`/`register_system`/`run_exclusive_system` call this right before registering their
+    /// own automatic-label copy, for the mirror image of the guard in
+    /// [`run_system_with_input`](Self::run_system_with_input): if `run_system_with_input` already
+    /// claimed this label first (e.g. for a `fn(): In = Out = ()`), the Io system it registered isn't
+    /// dispatchable through `run_system_at`, so it must give up the label rather than stick around
+    /// and make every future [`run_systems_by_label`](Self::run_systems_by_label) on it panic. The Io
+    /// system itself is untouched (and stays cached/runnable via `run_system_with_input`); it just stops
+    /// being discoverable under this label.
+    ///
+    /// This only guards automatic [`SystemTypeIdLabel`]s, the one kind of label collision that can arise
+    /// without a caller doing it on purpose. Manual registration (e.g.
+    /// [`register_system_with_labels`](Self::register_system_with_labels)) with an explicit label that
+    /// happens to match an `Io` system's label is (like any other manually-chosen label collision) the
+    /// caller's responsibility.
+    fn reclaim_label_from_io<L: SystemLabel>(&mut self, label: L) {
+        let boxed_label: Box<dyn SystemLabel> = Box::new(label);
+        self.reclaim_boxed_label_from_io(&boxed_label);
+    }
+
+    /// As [`reclaim_label_from_io`](Self::reclaim_label_from_io), but for callers (`run_boxed_system`,
+    /// `run_boxed_exclusive_system`) that already have their label boxed.
+    fn reclaim_boxed_label_from_io(&mut self, label: &Box<dyn SystemLabel>) {
+        if let Some(ids) = self.labels.get(label) {
+            if ids.iter().all(|id| id.storage == SystemStorage::Io) {
+                self.labels.remove(label);
+            }
+        }
+    }
+
+    /// Removes the system identified by `system_id` from the [`SystemRegistry`],
+    /// pruning it from every [`SystemLabel`] it was registered under.
+    ///
+    /// Returns [`SystemRegistryError::SystemIdNotFound`] if `system_id` is not currently registered,
+    /// for instance because it was already unregistered.
+    pub fn unregister_system(&mut self, system_id: SystemId) -> Result<(), SystemRegistryError> {
+        let removed = match system_id.storage {
+            SystemStorage::Parallel => self.systems.remove(system_id.index, system_id.generation),
+            SystemStorage::Exclusive => self
+                .exclusive_systems
+                .remove(system_id.index, system_id.generation),
+            SystemStorage::Io => self
+                .io_systems
+                .remove(system_id.index, system_id.generation),
+        };
+
+        if removed {
+            self.prune_labels(system_id);
+            self.prune_io_system_keys(system_id);
+            Ok(())
+        } else {
+            Err(SystemRegistryError::SystemIdNotFound(system_id))
+        }
+    }
+
+    /// Removes every system registered under `label` from the [`SystemRegistry`],
+    /// pruning each from every other label it may also have been registered under.
+    ///
+    /// Returns [`SystemRegistryError::LabelNotFound`] if no system is registered under `label`.
+    pub fn unregister_systems_by_label<L: SystemLabel>(
+        &mut self,
+        label: L,
+    ) -> Result<(), SystemRegistryError> {
         let boxed_label: Box<dyn SystemLabel> = Box::new(label);
-        let vec_of_indexes = self.labels.get(&boxed_label)?;
-        vec_of_indexes.iter().next().copied()
+        match self.labels.remove(&boxed_label) {
+            Some(system_ids) => {
+                for system_id in system_ids {
+                    match system_id.storage {
+                        SystemStorage::Parallel => {
+                            self.systems.remove(system_id.index, system_id.generation);
+                        }
+                        SystemStorage::Exclusive => {
+                            self.exclusive_systems
+                                .remove(system_id.index, system_id.generation);
+                        }
+                        SystemStorage::Io => {
+                            self.io_systems
+                                .remove(system_id.index, system_id.generation);
+                        }
+                    }
+                    self.prune_labels(system_id);
+                    self.prune_io_system_keys(system_id);
+                }
+                Ok(())
+            }
+            None => Err(SystemRegistryError::LabelNotFound(boxed_label)),
+        }
+    }
+
+    /// Prunes `system_id` from every label entry that still points at it (e.g. other labels it was also registered under).
+    fn prune_labels(&mut self, system_id: SystemId) {
+        self.labels.retain(|_, system_ids| {
+            system_ids.retain(|&id| id != system_id);
+            !system_ids.is_empty()
+        });
+    }
+
+    /// Prunes `system_id` from `io_system_keys`, if it refers to one; a no-op for the other storages.
+    fn prune_io_system_keys(&mut self, system_id: SystemId) {
+        if system_id.storage != SystemStorage::Io {
+            return;
+        }
+        self.io_system_keys.retain(|_, id| *id != system_id);
     }
 
     /// Runs the set of systems corresponding to the provided [`SystemLabel`] on the [`World`] a single time.
@@ -257,10 +591,10 @@ impl SystemRegistry {
         let boxed_label = callback.label;
 
         match self.labels.get(&boxed_label) {
-            Some(matching_indexes) => {
+            Some(matching_ids) => {
                 // Loop over the system in registration order
-                for index in matching_indexes.clone() {
-                    self.run_system_at_index(world, index);
+                for system_id in matching_ids.clone() {
+                    self.run_system_at(world, system_id);
                 }
 
                 Ok(())
@@ -280,17 +614,284 @@ impl SystemRegistry {
         system: S,
     ) {
         let automatic_system_label: SystemTypeIdLabel<S> = SystemTypeIdLabel::new();
-        let index = if self.is_label_registered(automatic_system_label) {
-            self.first_registered_index(automatic_system_label).unwrap()
+        let system_id = if let Some(system_id) = self.first_runnable_system_id(automatic_system_label)
+        {
+            system_id
         } else {
+            self.reclaim_label_from_io(automatic_system_label);
             let boxed_system: Box<dyn System<In = (), Out = ()>> =
                 Box::new(IntoSystem::into_system(system));
             let labels = boxed_system.default_labels();
             self.register_boxed_system_with_labels(world, boxed_system, labels)
         };
 
-        self.run_system_at_index(world, index);
+        self.run_system_at(world, system_id);
+    }
+
+    /// Runs an already-boxed `()`-in/`()`-out system, reusing a previously-registered system with a
+    /// matching default label if one exists, mirroring the caching behavior of [`run_system`](Self::run_system).
+    ///
+    /// Used by [`World`] to run systems deferred via [`SystemRegistryQueue`], where the concrete system
+    /// type has already been erased into a `Box<dyn System<..>>` by the time it reaches the registry.
+    fn run_boxed_system(
+        &mut self,
+        world: &mut World,
+        boxed_system: Box<dyn System<In = (), Out = ()>>,
+    ) {
+        let labels = boxed_system.default_labels();
+        let existing_system_id = labels
+            .first()
+            .and_then(|label| first_runnable_id(self.labels.get(label)?));
+
+        let system_id = match existing_system_id {
+            Some(system_id) => system_id,
+            None => {
+                if let Some(label) = labels.first() {
+                    self.reclaim_boxed_label_from_io(label);
+                }
+                self.register_boxed_system_with_labels(world, boxed_system, labels)
+            }
+        };
+
+        self.run_system_at(world, system_id);
     }
+
+    /// Runs the supplied system on the [`World`] a single time, passing in the given `input` and returning its output.
+    ///
+    /// This is the `In`/`Out`-generic counterpart to [`run_system`](SystemRegistry::run_system):
+    /// system state is keyed by its input type, output type and [`SystemTypeIdLabel`], then reused between calls,
+    /// so [`Local`](crate::system::Local) variables and change detection work correctly here too.
+    ///
+    /// Unlike `run_system`, this doesn't hand back a [`SystemId`]: ordinarily, the system is registered
+    /// under its [`SystemTypeIdLabel`] just like `run_system`'s auto-registered systems are, so it can
+    /// still be removed later via [`unregister_systems_by_label`](Self::unregister_systems_by_label) —
+    /// except if a `run_system`/`register_system`/`run_exclusive_system` call already holds that same
+    /// label (possible for a `fn(): In = Out = ()`, which satisfies both APIs' bounds), in which case
+    /// this system defers to that registration and isn't itself reachable by label at all.
+    pub fn run_system_with_input<
+        I: 'static,
+        O: 'static,
+        Params,
+        S: IntoSystem<I, O, Params> + 'static,
+    >(
+        &mut self,
+        world: &mut World,
+        system: S,
+        input: I,
+    ) -> O {
+        let automatic_system_label: SystemTypeIdLabel<S> = SystemTypeIdLabel::new();
+        let key = IoSystemKey::new::<I, O>(Box::new(automatic_system_label));
+
+        let system_id = if let Some(&system_id) = self.io_system_keys.get(&key) {
+            system_id
+        } else {
+            let mut boxed_system: Box<dyn System<In = I, Out = O>> =
+                Box::new(IntoSystem::into_system(system));
+            boxed_system.initialize(world);
+
+            let (index, generation) = self.io_systems.insert(IoStoredSystem {
+                system: Box::new(boxed_system),
+            });
+            let system_id = SystemId {
+                index,
+                generation,
+                storage: SystemStorage::Io,
+            };
+
+            // A `fn(): In = Out = ()` satisfies both this method's bounds and `run_system`'s, so it's
+            // possible (if unusual) for a `run_system`/`register_system`/`run_exclusive_system` call to
+            // already hold this `SystemTypeIdLabel`. In that case, leave that registration as the label's
+            // sole owner rather than adding this one alongside it: `run_system_at` can't dispatch an `Io`
+            // system, so label-based dispatch (e.g. `run_systems_by_label`) would panic if it did.
+            if !self.is_label_registered(automatic_system_label) {
+                self.insert_labels(system_id, vec![Box::new(automatic_system_label)]);
+            }
+            self.io_system_keys.insert(key, system_id);
+
+            system_id
+        };
+
+        let stored_system = self
+            .io_systems
+            .get_mut(system_id.index, system_id.generation)
+            .expect("SystemId was not found in the SystemRegistry; this is a bug in SystemRegistry");
+
+        let boxed_system = stored_system
+            .system
+            .downcast_mut::<Box<dyn System<In = I, Out = O>>>()
+            .expect(
+                "IoSystemKey collision: stored system does not match the requested In/Out types",
+            );
+
+        let output = boxed_system.run(input, world);
+        boxed_system.apply_buffers(world);
+        output
+    }
+
+    /// Register an exclusive system (a system taking `&mut World`, via [`IntoExclusiveSystem`]) with any number of [`SystemLabel`]s.
+    ///
+    /// Exclusive systems are stored separately from ordinary systems, but share the same label lookup:
+    /// [`run_systems_by_label`](SystemRegistry::run_systems_by_label) dispatches to whichever storage a label resolves to,
+    /// so an exclusive cleanup routine can be registered and triggered the same way as any other system.
+    ///
+    /// Returns the [`SystemId`] of the registered system, which can be passed to
+    /// [`unregister_system`](SystemRegistry::unregister_system) to remove it again.
+    pub fn register_exclusive_system_with_labels<
+        Params,
+        S: IntoExclusiveSystem<Params>,
+        LI: IntoIterator<Item = L>,
+        L: SystemLabel,
+    >(
+        &mut self,
+        world: &mut World,
+        system: S,
+        labels: LI,
+    ) -> SystemId
+    where
+        S::System: 'static,
+    {
+        let boxed_system: Box<dyn ExclusiveSystem> = Box::new(system.exclusive_system());
+
+        let collected_labels = labels
+            .into_iter()
+            .map(|label| {
+                let boxed_label: Box<dyn SystemLabel> = Box::new(label);
+                boxed_label
+            })
+            .collect();
+
+        self.register_boxed_exclusive_system_with_labels(world, boxed_system, collected_labels)
+    }
+
+    /// A more exacting version of [`register_exclusive_system_with_labels`](Self::register_exclusive_system_with_labels).
+    ///
+    /// Returns the [`SystemId`] that this new exclusive system is stored at.
+    pub fn register_boxed_exclusive_system_with_labels(
+        &mut self,
+        world: &mut World,
+        mut boxed_system: Box<dyn ExclusiveSystem>,
+        labels: Vec<Box<dyn SystemLabel>>,
+    ) -> SystemId {
+        boxed_system.initialize(world);
+
+        let (index, generation) = self.exclusive_systems.insert(ExclusiveStoredSystem {
+            system: boxed_system,
+        });
+        let system_id = SystemId {
+            index,
+            generation,
+            storage: SystemStorage::Exclusive,
+        };
+        self.insert_labels(system_id, labels);
+
+        system_id
+    }
+
+    /// Runs the supplied exclusive system (a system taking `&mut World`) on the [`World`] a single time.
+    ///
+    /// Mirrors [`run_system`](SystemRegistry::run_system): state is cached and reused across calls,
+    /// keyed by the system's [`SystemTypeIdLabel`].
+    pub fn run_exclusive_system<Params, S: IntoExclusiveSystem<Params>>(
+        &mut self,
+        world: &mut World,
+        system: S,
+    ) where
+        S::System: 'static,
+    {
+        let automatic_system_label: SystemTypeIdLabel<S> = SystemTypeIdLabel::new();
+        let system_id = if let Some(system_id) = self.first_runnable_system_id(automatic_system_label)
+        {
+            system_id
+        } else {
+            self.reclaim_label_from_io(automatic_system_label);
+            let boxed_system: Box<dyn ExclusiveSystem> = Box::new(system.exclusive_system());
+            self.register_boxed_exclusive_system_with_labels(
+                world,
+                boxed_system,
+                vec![Box::new(automatic_system_label)],
+            )
+        };
+
+        self.run_system_at(world, system_id);
+    }
+
+    /// Runs an already-boxed exclusive system, reusing a previously-registered system stored under
+    /// `label` if one exists, mirroring the caching behavior of [`run_exclusive_system`](Self::run_exclusive_system).
+    ///
+    /// Used by [`World`] to run exclusive systems deferred via [`SystemRegistryQueue`], where the
+    /// concrete system type has already been erased into a `Box<dyn ExclusiveSystem>` by the time it
+    /// reaches the registry.
+    fn run_boxed_exclusive_system(
+        &mut self,
+        world: &mut World,
+        boxed_system: Box<dyn ExclusiveSystem>,
+        label: Box<dyn SystemLabel>,
+    ) {
+        let existing_system_id = self
+            .labels
+            .get(&label)
+            .and_then(|ids| first_runnable_id(ids));
+
+        let system_id = match existing_system_id {
+            Some(system_id) => system_id,
+            None => {
+                self.reclaim_boxed_label_from_io(&label);
+                self.register_boxed_exclusive_system_with_labels(world, boxed_system, vec![label])
+            }
+        };
+
+        self.run_system_at(world, system_id);
+    }
+}
+
+/// The maximum number of re-entrant runs that [`SystemRegistryQueue`] will drain in a single chain
+/// before giving up and returning [`SystemRegistryError::RecursionLimitExceeded`].
+///
+/// This exists to turn runaway recursion (a system that keeps requesting more runs of itself)
+/// into a clean error instead of an infinite loop.
+const MAX_RUN_RECURSION_DEPTH: usize = 128;
+
+/// Tracks system runs requested while a [`SystemRegistry`] run is already in progress, to support recursion.
+///
+/// Unlike [`SystemRegistry`], this resource is never checked out of the [`World`] via
+/// [`resource_scope`](World::resource_scope): it stays put, so it can be used to detect that a run is
+/// already underway (the [`SystemRegistry`] is temporarily missing from the `World`) and queue the new
+/// request up instead of panicking. The outermost run then drains this queue in a loop until it's empty,
+/// which is what allows `commands.run_system(..)` called from inside a running system
+/// (see the `system_recursion` test) to work without overflowing the stack.
+#[derive(Default)]
+struct SystemRegistryQueue {
+    /// Pending runs, in the order they were requested; drained front-to-back so siblings queued from
+    /// the same `apply_buffers` call execute in that order, matching how every other `Command` applies.
+    pending: VecDeque<PendingRun>,
+    /// How many items still belong to the recursion level currently being drained, before the next
+    /// level (one level deeper than the run that queued them) begins. `0` means the next item popped
+    /// starts a new, deeper level.
+    current_level_remaining: usize,
+    /// How many levels deep the current chain of re-entrant runs has gone; reset to `0` once the queue runs dry.
+    ///
+    /// This only increases when draining moves to a level deeper than the one it started at, so a single
+    /// run that queues many independent, non-recursive siblings in one `apply_buffers` call doesn't trip
+    /// [`MAX_RUN_RECURSION_DEPTH`] on its own; only genuine nesting does.
+    depth: usize,
+}
+
+/// A deferred request to run a system or [`Callback`], queued up by [`SystemRegistryQueue`].
+enum PendingRun {
+    Callback(Callback),
+    /// A [`Callback`] queued by [`RunSystemsByLabelCommand`], paired with the policy to apply if,
+    /// once it actually runs, no system turns out to be registered under its label.
+    ///
+    /// Carrying `on_missing` alongside the callback (rather than applying the policy only at the
+    /// command's initial call site) ensures it's still honored if the callback ends up deferred and
+    /// drained later by [`SystemRegistryQueue`], instead of bubbling a [`LabelNotFound`](SystemRegistryError::LabelNotFound)
+    /// error out to whatever unrelated run happens to be draining the queue at the time.
+    CallbackWithOnMissing(Callback, OnMissingLabel),
+    System(Box<dyn System<In = (), Out = ()>>),
+    /// An exclusive system queued by [`World::run_exclusive_system`], paired with the
+    /// [`SystemTypeIdLabel`] it would otherwise have been registered and looked up under, since a
+    /// boxed `dyn ExclusiveSystem` can't carry that type information itself.
+    ExclusiveSystem(Box<dyn ExclusiveSystem>, Box<dyn SystemLabel>),
 }
 
 impl World {
@@ -298,10 +899,13 @@ impl World {
     ///
     /// Calls the method of the same name on [`SystemRegistry`].
     #[inline]
-    pub fn register_system<Params, S: IntoSystem<(), (), Params> + 'static>(&mut self, system: S) {
+    pub fn register_system<Params, S: IntoSystem<(), (), Params> + 'static>(
+        &mut self,
+        system: S,
+    ) -> SystemId {
         self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.register_system(world, system);
-        });
+            registry.register_system(world, system)
+        })
     }
 
     /// Register system a system with any number of [`SystemLabel`]s.
@@ -316,42 +920,266 @@ impl World {
         &mut self,
         system: S,
         labels: LI,
-    ) {
+    ) -> SystemId {
         self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.register_system_with_labels(world, system, labels);
-        });
+            registry.register_system_with_labels(world, system, labels)
+        })
     }
 
     /// Runs the supplied system on the [`World`] a single time.
     ///
-    /// Calls the method of the same name on [`SystemRegistry`].
+    /// Calls the method of the same name on [`SystemRegistry`], unless this is called while a
+    /// [`SystemRegistry`] run is already in progress (e.g. from inside a running system via
+    /// `commands.run_system`), in which case the run is deferred to a queue drained by the
+    /// outermost run. See [`SystemRegistryQueue`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if chained re-entrant runs exceed [`MAX_RUN_RECURSION_DEPTH`].
     #[inline]
     pub fn run_system<Params, S: IntoSystem<(), (), Params> + 'static>(&mut self, system: S) {
+        let boxed_system: Box<dyn System<In = (), Out = ()>> =
+            Box::new(IntoSystem::into_system(system));
+        self.run_or_defer(PendingRun::System(boxed_system))
+            .expect("recursion limit exceeded while running systems via the SystemRegistry");
+    }
+
+    /// Runs the supplied system on the [`World`] a single time, passing in `input` and returning its output.
+    ///
+    /// Calls the method of the same name on [`SystemRegistry`]. Unlike [`run_system`](World::run_system),
+    /// this is *not* deferred via [`SystemRegistryQueue`] if a [`SystemRegistry`] run is already in
+    /// progress: since its `Out` value must be returned to the caller synchronously, there's no later
+    /// point at which a deferred run could produce it. Calling this from inside an already-running
+    /// system (or an exclusive system) will panic instead of recursing cleanly; see the module-level
+    /// `# Limitations` section.
+    #[inline]
+    pub fn run_system_with_input<
+        I: 'static,
+        O: 'static,
+        Params,
+        S: IntoSystem<I, O, Params> + 'static,
+    >(
+        &mut self,
+        system: S,
+        input: I,
+    ) -> O {
         self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.run_system(world, system);
-        });
+            registry.run_system_with_input(world, system, input)
+        })
     }
 
-    /// Runs the systems corresponding to the supplied [`SystemLabel`] on the [`World`] a single time.
+    /// Register an exclusive system (a system taking `&mut World`) with any number of [`SystemLabel`]s.
     ///
     /// Calls the method of the same name on [`SystemRegistry`].
+    pub fn register_exclusive_system_with_labels<
+        Params,
+        S: IntoExclusiveSystem<Params>,
+        LI: IntoIterator<Item = L>,
+        L: SystemLabel,
+    >(
+        &mut self,
+        system: S,
+        labels: LI,
+    ) -> SystemId
+    where
+        S::System: 'static,
+    {
+        self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+            registry.register_exclusive_system_with_labels(world, system, labels)
+        })
+    }
+
+    /// Runs the supplied exclusive system (a system taking `&mut World`) on the [`World`] a single time.
+    ///
+    /// Calls the method of the same name on [`SystemRegistry`], unless this is called while a
+    /// [`SystemRegistry`] run is already in progress (e.g. an exclusive system's body calling
+    /// `world.run_system(..)` or `world.run_systems_by_label(..)`), in which case the run is
+    /// deferred to a queue drained by the outermost run. See [`SystemRegistryQueue`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if chained re-entrant runs exceed [`MAX_RUN_RECURSION_DEPTH`].
+    #[inline]
+    pub fn run_exclusive_system<Params, S: IntoExclusiveSystem<Params>>(&mut self, system: S)
+    where
+        S::System: 'static,
+    {
+        let automatic_system_label: SystemTypeIdLabel<S> = SystemTypeIdLabel::new();
+        let boxed_system: Box<dyn ExclusiveSystem> = Box::new(system.exclusive_system());
+        self.run_or_defer(PendingRun::ExclusiveSystem(
+            boxed_system,
+            Box::new(automatic_system_label),
+        ))
+        .expect("recursion limit exceeded while running systems via the SystemRegistry");
+    }
+
+    /// Runs the systems corresponding to the supplied [`SystemLabel`] on the [`World`] a single time.
+    ///
+    /// Calls the method of the same name on [`SystemRegistry`], deferring to the
+    /// [`SystemRegistryQueue`] if a run is already in progress (see [`run_system`](World::run_system)).
     #[inline]
     pub fn run_systems_by_label<L: SystemLabel>(
         &mut self,
         label: L,
     ) -> Result<(), SystemRegistryError> {
-        self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.run_systems_by_label(world, label)
+        self.run_callback(Callback {
+            label: Box::new(label),
         })
     }
 
     /// Run the systems corresponding to the label stored in the provided [`Callback`]
     ///
-    /// Calls the method of the same name on [`SystemRegistry`].
+    /// Calls the method of the same name on [`SystemRegistry`], deferring to the
+    /// [`SystemRegistryQueue`] if a run is already in progress (see [`run_system`](World::run_system)).
     #[inline]
     pub fn run_callback(&mut self, callback: Callback) -> Result<(), SystemRegistryError> {
-        self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry.run_callback(world, callback)
+        self.run_or_defer(PendingRun::Callback(callback))
+    }
+
+    /// Runs (or defers) `callback` on behalf of [`RunSystemsByLabelCommand`], applying `on_missing`
+    /// wherever it actually ends up executing, whether that's immediately or later, once drained
+    /// from [`SystemRegistryQueue`] if a run is already in progress.
+    fn run_callback_with_on_missing(
+        &mut self,
+        callback: Callback,
+        on_missing: OnMissingLabel,
+    ) -> Result<(), SystemRegistryError> {
+        self.run_or_defer(PendingRun::CallbackWithOnMissing(callback, on_missing))
+    }
+
+    /// Runs (or defers, per [`SystemRegistryQueue`]) the given pending system run.
+    fn run_or_defer(&mut self, pending: PendingRun) -> Result<(), SystemRegistryError> {
+        if !self.contains_resource::<SystemRegistry>() {
+            // A run is already in progress further up the call stack (the `SystemRegistry` is
+            // checked out via `resource_scope`); queue this one up for the outermost run to drain.
+            self.system_registry_queue_mut().pending.push_back(pending);
+            return Ok(());
+        }
+
+        self.run_pending(pending)?;
+        self.drain_system_registry_queue()
+    }
+
+    /// Runs a single [`PendingRun`] against the (currently checked-in) [`SystemRegistry`] resource.
+    fn run_pending(&mut self, pending: PendingRun) -> Result<(), SystemRegistryError> {
+        match pending {
+            PendingRun::Callback(callback) => {
+                self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+                    registry.run_callback(world, callback)
+                })
+            }
+            PendingRun::CallbackWithOnMissing(callback, on_missing) => {
+                let result = self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+                    registry.run_callback(world, callback)
+                });
+                match result {
+                    Ok(()) => Ok(()),
+                    Err(SystemRegistryError::LabelNotFound(_)) => {
+                        match on_missing {
+                            OnMissingLabel::Ignore => {}
+                            OnMissingLabel::Warn => {
+                                warn!("RunSystemsByLabelCommand: no system was registered under the requested label");
+                            }
+                            OnMissingLabel::Panic => {
+                                panic!("RunSystemsByLabelCommand: no system was registered under the requested label");
+                            }
+                        }
+                        Ok(())
+                    }
+                    // Any other error (e.g. hitting the re-entrant run recursion limit) is not about a
+                    // missing label, so `on_missing` doesn't apply to it.
+                    Err(err) => Err(err),
+                }
+            }
+            PendingRun::System(boxed_system) => {
+                self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+                    registry.run_boxed_system(world, boxed_system);
+                });
+                Ok(())
+            }
+            PendingRun::ExclusiveSystem(boxed_system, label) => {
+                self.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
+                    registry.run_boxed_exclusive_system(world, boxed_system, label);
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the [`SystemRegistryQueue`] resource, initializing it first if necessary.
+    fn system_registry_queue_mut(&mut self) -> Mut<SystemRegistryQueue> {
+        if !self.contains_resource::<SystemRegistryQueue>() {
+            self.init_resource::<SystemRegistryQueue>();
+        }
+        self.resource_mut::<SystemRegistryQueue>()
+    }
+
+    /// Drains [`SystemRegistryQueue`] front-to-back (mirroring real recursion order: siblings queued
+    /// from the same `apply_buffers` call run in the order they were requested), stopping with
+    /// [`SystemRegistryError::RecursionLimitExceeded`] if a chain of re-entrant runs nests deeper than
+    /// [`MAX_RUN_RECURSION_DEPTH`].
+    ///
+    /// Depth only increases when draining moves on to runs queued by the *previous* level (i.e. genuine
+    /// nesting), not for every item popped; a single level can contain any number of independent,
+    /// non-recursive siblings without tripping the limit.
+    fn drain_system_registry_queue(&mut self) -> Result<(), SystemRegistryError> {
+        loop {
+            let pending = {
+                let mut queue = self.system_registry_queue_mut();
+
+                if queue.current_level_remaining == 0 {
+                    if queue.pending.is_empty() {
+                        queue.depth = 0;
+                        return Ok(());
+                    }
+
+                    queue.depth += 1;
+                    if queue.depth > MAX_RUN_RECURSION_DEPTH {
+                        let dropped = queue.pending.len();
+                        queue.pending.clear();
+                        queue.depth = 0;
+                        queue.current_level_remaining = 0;
+                        warn!(
+                            "SystemRegistryQueue: recursion limit ({MAX_RUN_RECURSION_DEPTH} levels) exceeded; \
+                             dropping {dropped} still-pending run(s)"
+                        );
+                        return Err(SystemRegistryError::RecursionLimitExceeded);
+                    }
+
+                    queue.current_level_remaining = queue.pending.len();
+                }
+
+                queue.current_level_remaining -= 1;
+                queue
+                    .pending
+                    .pop_front()
+                    .expect("current_level_remaining was non-zero, but the queue was empty; this is a bug in SystemRegistryQueue")
+            };
+
+            self.run_pending(pending)?;
+        }
+    }
+
+    /// Removes the system identified by `system_id` from the [`SystemRegistry`] resource.
+    ///
+    /// Calls the method of the same name on [`SystemRegistry`].
+    #[inline]
+    pub fn unregister_system(&mut self, system_id: SystemId) -> Result<(), SystemRegistryError> {
+        self.resource_scope(|_world, mut registry: Mut<SystemRegistry>| {
+            registry.unregister_system(system_id)
+        })
+    }
+
+    /// Removes every system registered under `label` from the [`SystemRegistry`] resource.
+    ///
+    /// Calls the method of the same name on [`SystemRegistry`].
+    #[inline]
+    pub fn unregister_systems_by_label<L: SystemLabel>(
+        &mut self,
+        label: L,
+    ) -> Result<(), SystemRegistryError> {
+        self.resource_scope(|_world, mut registry: Mut<SystemRegistry>| {
+            registry.unregister_systems_by_label(label)
         })
     }
 }
@@ -389,25 +1217,113 @@ impl<Params: Send + Sync + 'static, S: IntoSystem<(), (), Params> + Send + Sync
     }
 }
 
+/// What [`RunSystemsByLabelCommand`] should do if, once applied, no system is registered under its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnMissingLabel {
+    /// Silently do nothing.
+    Ignore,
+    /// Log a [`warn!`] and continue.
+    Warn,
+    /// Panic. This matches this command's original (unconditional) behavior.
+    Panic,
+}
+
+impl Default for OnMissingLabel {
+    /// Defaults to [`OnMissingLabel::Panic`], matching this command's original (unconditional) behavior.
+    fn default() -> Self {
+        OnMissingLabel::Panic
+    }
+}
+
 /// The [`Command`] type for [`SystemRegistry::run_systems_by_label`]
 #[derive(Debug, Clone)]
 pub struct RunSystemsByLabelCommand {
     pub callback: Callback,
+    /// What to do if, once this command is applied, no system is registered under `callback`'s label.
+    pub on_missing: OnMissingLabel,
 }
 
 impl Command for RunSystemsByLabelCommand {
     #[inline]
     fn write(self, world: &mut World) {
-        world.resource_scope(|world, mut registry: Mut<SystemRegistry>| {
-            registry
-                .run_callback(world, self.callback)
-                // Ideally this error should be handled more gracefully,
-                // but that's blocked on a full error handling solution for commands
-                .unwrap();
+        // `on_missing` is applied inside `run_callback_with_on_missing`, wherever the callback
+        // actually runs, rather than here: if a run is already in progress (e.g. this command is
+        // being applied from inside a running system), the callback may not execute until later,
+        // once drained from `SystemRegistryQueue`.
+        if let Err(err) = world.run_callback_with_on_missing(self.callback, self.on_missing) {
+            panic!("RunSystemsByLabelCommand failed: {err:?}");
+        }
+    }
+}
+
+/// A builder returned by [`RunSystemsByLabelExt::run_systems_by_label`], used to configure what should
+/// happen if no system turns out to be registered under the label, via [`on_missing`](Self::on_missing).
+///
+/// The underlying [`RunSystemsByLabelCommand`] is only queued once [`queue`](Self::queue) is called
+/// explicitly; dropping this builder without calling it does nothing.
+#[must_use = "RunSystemsByLabelBuilder does nothing until `queue` is called"]
+pub struct RunSystemsByLabelBuilder<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+    callback: Callback,
+    on_missing: OnMissingLabel,
+}
+
+impl<'w, 's, 'a> RunSystemsByLabelBuilder<'w, 's, 'a> {
+    /// Sets the policy to apply if, once this command is applied, no system is registered under the label.
+    ///
+    /// Defaults to [`OnMissingLabel::Panic`] if left unset.
+    #[must_use]
+    pub fn on_missing(mut self, on_missing: OnMissingLabel) -> Self {
+        self.on_missing = on_missing;
+        self
+    }
+
+    /// Queues the underlying [`RunSystemsByLabelCommand`] to be run once commands are applied.
+    ///
+    /// This must be called explicitly: a builder that's dropped without calling `queue` (e.g. bound
+    /// to a variable that goes out of scope, or never reached due to an early return) never runs.
+    #[inline]
+    pub fn queue(self) {
+        self.commands.add(RunSystemsByLabelCommand {
+            callback: self.callback,
+            on_missing: self.on_missing,
         });
     }
 }
 
+/// Extension trait adding [`run_systems_by_label`](Self::run_systems_by_label) to [`Commands`](crate::system::Commands).
+///
+/// This is the fallible counterpart to `commands.run_system`: unlike running a single system directly,
+/// running systems by label can fail if no system is registered under that label (e.g. an optional,
+/// event-style callback that nothing has subscribed to yet).
+pub trait RunSystemsByLabelExt<'w, 's> {
+    /// Returns a builder for a command that runs the systems registered under `label`.
+    ///
+    /// What happens if no system is registered under `label` can be configured via
+    /// [`on_missing`](RunSystemsByLabelBuilder::on_missing); defaults to [`OnMissingLabel::Panic`]
+    /// if left unconfigured. The command is only queued once the returned builder's
+    /// [`queue`](RunSystemsByLabelBuilder::queue) is called explicitly.
+    fn run_systems_by_label<L: SystemLabel>(
+        &mut self,
+        label: L,
+    ) -> RunSystemsByLabelBuilder<'w, 's, '_>;
+}
+
+impl<'w, 's> RunSystemsByLabelExt<'w, 's> for Commands<'w, 's> {
+    fn run_systems_by_label<L: SystemLabel>(
+        &mut self,
+        label: L,
+    ) -> RunSystemsByLabelBuilder<'w, 's, '_> {
+        RunSystemsByLabelBuilder {
+            commands: self,
+            callback: Callback {
+                label: Box::new(label),
+            },
+            on_missing: OnMissingLabel::default(),
+        }
+    }
+}
+
 /// A struct that stores a boxed [`SystemLabel`], used to cause a [`SystemRegistry`] to run systems.
 ///
 /// This might be stored as a component, used as an event, or arranged in a queue stored in a resource.
@@ -459,10 +1375,19 @@ pub enum SystemRegistryError {
     ///
     /// Did you forget to register it?
     LabelNotFound(Box<dyn SystemLabel>),
+    /// A [`SystemId`] was used to look up or unregister a system, but it is no longer registered.
+    ///
+    /// This happens if the system was already unregistered, e.g. via [`SystemRegistry::unregister_system`].
+    SystemIdNotFound(SystemId),
+    /// A chain of re-entrant system runs (e.g. a system that recursively runs itself via `Commands`)
+    /// exceeded [`MAX_RUN_RECURSION_DEPTH`] without terminating.
+    RecursionLimitExceeded,
 }
 
 mod tests {
+    use super::MAX_RUN_RECURSION_DEPTH;
     use crate::prelude::*;
+    use crate::system::{OnMissingLabel, RunSystemsByLabelExt, SystemRegistryError, SystemTypeIdLabel};
 
     #[derive(Default, PartialEq, Debug)]
     struct Counter(u8);
@@ -506,6 +1431,152 @@ mod tests {
         assert_eq!(*world.resource::<Counter>(), Counter(2));
     }
 
+    #[test]
+    fn register_system_returns_system_id() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // `register_system` must also hand back a `SystemId`, so a system auto-registered under
+        // its `SystemTypeIdLabel` can still be unregistered later (e.g. a callback tied to a despawned entity).
+        let system_id = world.register_system(count_up);
+        world.run_system(count_up);
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        world.unregister_system(system_id).unwrap();
+        assert!(matches!(
+            world.unregister_system(system_id),
+            Err(SystemRegistryError::SystemIdNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn unregister_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let system_id = world.register_system_with_labels(count_up, ["count"]);
+        world.run_systems_by_label("count").unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        world.unregister_system(system_id).unwrap();
+        // The label no longer has any systems registered under it.
+        assert!(matches!(
+            world.run_systems_by_label("count"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+        // Unregistering an already-unregistered `SystemId` is an error, not a panic.
+        assert!(matches!(
+            world.unregister_system(system_id),
+            Err(SystemRegistryError::SystemIdNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn unregister_systems_by_label() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.register_system_with_labels(count_up, ["count", "all"]);
+        world.register_system_with_labels(count_up, ["count"]);
+        world.run_systems_by_label("count").unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        world.unregister_systems_by_label("count").unwrap();
+        // Both systems are gone, including from the other label one of them was also registered under.
+        assert!(matches!(
+            world.run_systems_by_label("count"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+        assert!(matches!(
+            world.run_systems_by_label("all"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+    }
+
+    #[allow(dead_code)]
+    fn count_up_exclusive(world: &mut World) {
+        world.resource_mut::<Counter>().0 += 1;
+    }
+
+    #[test]
+    fn run_exclusive_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert_eq!(*world.resource::<Counter>(), Counter(0));
+        world.run_exclusive_system(count_up_exclusive);
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+    }
+
+    #[test]
+    fn run_exclusive_system_by_label() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // An exclusive system and a regular system can be registered under, and dispatched through, the same label.
+        world.register_exclusive_system_with_labels(count_up_exclusive, ["count"]);
+        world.register_system_with_labels(count_up, ["count"]);
+        world.run_systems_by_label("count").unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    fn run_exclusive_system_dispatches_nested_runs_before_returning() {
+        fn count_up_twice_via_nested_runs(world: &mut World) {
+            // Both of these would be silently queued into `SystemRegistryQueue` (the `SystemRegistry`
+            // is checked out while this exclusive system runs), so this exercises that the outer
+            // `run_exclusive_system` drains them before returning, rather than leaving them to be
+            // picked up by some unrelated later `run_system`/`run_systems_by_label` call.
+            world.run_system(count_up);
+            world.run_systems_by_label("count").unwrap();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.register_system_with_labels(count_up, ["count"]);
+        world.run_exclusive_system(count_up_twice_via_nested_runs);
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    fn unregister_exclusive_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let system_id = world.register_exclusive_system_with_labels(count_up_exclusive, ["count"]);
+        world.run_systems_by_label("count").unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        world.unregister_system(system_id).unwrap();
+        // The label no longer has any systems registered under it.
+        assert!(matches!(
+            world.run_systems_by_label("count"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+        // Unregistering an already-unregistered `SystemId` is an error, not a panic.
+        assert!(matches!(
+            world.unregister_system(system_id),
+            Err(SystemRegistryError::SystemIdNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn unregister_exclusive_and_parallel_systems_by_label() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // Mix an exclusive system and a regular (parallel) system under the same label.
+        world.register_exclusive_system_with_labels(count_up_exclusive, ["count", "all"]);
+        world.register_system_with_labels(count_up, ["count"]);
+        world.run_systems_by_label("count").unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        world.unregister_systems_by_label("count").unwrap();
+        // Both the exclusive and parallel systems are gone, including from the other label the
+        // exclusive one was also registered under.
+        assert!(matches!(
+            world.run_systems_by_label("count"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+        assert!(matches!(
+            world.run_systems_by_label("all"),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+    }
+
     #[allow(dead_code)]
     fn spawn_entity(mut commands: Commands) {
         commands.spawn();
@@ -520,6 +1591,33 @@ mod tests {
         assert_eq!(world.entities.len(), 1);
     }
 
+    #[test]
+    fn run_systems_by_label_command_ignores_missing_label() {
+        fn fire_missing_callback(mut commands: Commands) {
+            commands
+                .run_systems_by_label("missing")
+                .on_missing(OnMissingLabel::Ignore)
+                .queue();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // Should not panic, even though nothing is registered under "missing".
+        world.run_system(fire_missing_callback);
+    }
+
+    #[test]
+    #[should_panic(expected = "no system was registered under the requested label")]
+    fn run_systems_by_label_command_panics_on_missing_label_by_default() {
+        fn fire_missing_callback(mut commands: Commands) {
+            commands.run_systems_by_label("missing").queue();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.run_system(fire_missing_callback);
+    }
+
     #[test]
     fn non_send_resources() {
         fn non_send_count_down(mut ns: NonSendMut<Counter>) {
@@ -586,10 +1684,139 @@ mod tests {
     }
 
     #[test]
-    // This is a known limitation;
-    // if this test passes the docs must be updated
-    // to reflect the ability to chain run_system commands
+    fn run_system_with_input() {
+        fn add_to_counter(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+            counter.0 += amount;
+            counter.0
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert_eq!(world.run_system_with_input(add_to_counter, 3), 3);
+        // State is cached and reused between calls, just like `run_system`.
+        assert_eq!(world.run_system_with_input(add_to_counter, 4), 7);
+        assert_eq!(*world.resource::<Counter>(), Counter(7));
+    }
+
+    /// Mirrors how `run_system_with_input` derives a system's label internally, so a test can name it
+    /// without spelling out the system's anonymous function-item type.
+    fn label_for<I: 'static, O: 'static, Params, S: IntoSystem<I, O, Params> + 'static>(
+        _system: &S,
+    ) -> SystemTypeIdLabel<S> {
+        SystemTypeIdLabel::new()
+    }
+
+    #[test]
+    fn unregister_system_registered_via_run_system_with_input() {
+        fn add_to_counter(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+            counter.0 += amount;
+            counter.0
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert_eq!(world.run_system_with_input(add_to_counter, 3), 3);
+
+        // A system registered via `run_system_with_input` is discoverable and removable under its
+        // `SystemTypeIdLabel`, just like `run_system`'s auto-registered systems are.
+        world
+            .unregister_systems_by_label(label_for(&add_to_counter))
+            .unwrap();
+        assert!(matches!(
+            world.unregister_systems_by_label(label_for(&add_to_counter)),
+            Err(SystemRegistryError::LabelNotFound(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot run an Io-registered system")]
+    fn run_systems_by_label_panics_on_io_registered_system() {
+        fn add_to_counter(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+            counter.0 += amount;
+            counter.0
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.run_system_with_input(add_to_counter, 3);
+
+        // Registering `add_to_counter` under its `SystemTypeIdLabel` makes it discoverable via
+        // `run_systems_by_label`, but it can't actually be run that way: only the original
+        // `run_system_with_input` caller has its concrete `In`/`Out` type.
+        world.run_systems_by_label(label_for(&add_to_counter)).ok();
+    }
+
+    #[test]
+    fn run_system_then_run_system_with_input_does_not_corrupt_shared_label() {
+        // `fn(): In = Out = ()` satisfies both `run_system`'s and `run_system_with_input`'s bounds,
+        // so the two can end up racing for the same automatic `SystemTypeIdLabel`.
+        fn tick(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        world.run_system(tick);
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        // This must not register a second, label-sharing copy of `tick`: doing so would make
+        // `run_systems_by_label`/a later `run_system` call liable to hit an unrunnable `Io` entry.
+        world.run_system_with_input(tick, ());
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        world.run_system(tick);
+        assert_eq!(*world.resource::<Counter>(), Counter(3));
+        world.run_systems_by_label(label_for(&tick)).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+    }
+
+    #[test]
+    fn run_system_with_input_then_run_system_reclaims_the_shared_label() {
+        // The reverse ordering of the test above: `run_system_with_input` claims the label first, so
+        // `run_system`'s later registration must reclaim it rather than leave the unrunnable `Io` entry
+        // sitting alongside (or in front of) its own runnable one.
+        fn tick(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        world.run_system_with_input(tick, ());
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+
+        world.run_system(tick);
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+
+        world.run_system(tick);
+        assert_eq!(*world.resource::<Counter>(), Counter(3));
+        world.run_systems_by_label(label_for(&tick)).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(4));
+    }
+
+    #[test]
     #[should_panic]
+    fn run_system_with_input_panics_when_called_from_nested_system() {
+        fn add_one(In(amount): In<u8>, mut counter: ResMut<Counter>) -> u8 {
+            counter.0 += amount;
+            counter.0
+        }
+
+        fn call_run_system_with_input(world: &mut World) {
+            world.run_system_with_input(add_one, 1);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        // Unlike `run_system`/`run_systems_by_label`, `run_system_with_input` isn't deferred via
+        // `SystemRegistryQueue`, since it must hand its `Out` value back to the caller synchronously.
+        // Calling it from inside an already-running system currently panics instead of recursing
+        // cleanly (see the module-level `# Limitations` section) -- this test documents that gap.
+        world.run_exclusive_system(call_run_system_with_input);
+    }
+
+    #[test]
     fn system_recursion() {
         fn count_to_ten(mut counter: ResMut<Counter>, mut commands: Commands) {
             counter.0 += 1;
@@ -604,4 +1831,63 @@ mod tests {
         world.run_system(count_to_ten);
         assert_eq!(*world.resource::<Counter>(), Counter(10));
     }
+
+    #[test]
+    #[should_panic(expected = "recursion limit exceeded")]
+    fn system_recursion_hits_depth_limit() {
+        fn recurse_forever(mut commands: Commands) {
+            commands.run_system(recurse_forever);
+        }
+
+        let mut world = World::new();
+        // Without a depth guard, this would recurse (via the deferred run queue) forever;
+        // it must instead fail cleanly rather than hang or overflow the stack.
+        world.run_system(recurse_forever);
+    }
+
+    #[derive(Default)]
+    struct Log(Vec<u8>);
+
+    #[test]
+    fn system_recursion_runs_siblings_in_request_order() {
+        fn log_1(mut log: ResMut<Log>) {
+            log.0.push(1);
+        }
+        fn log_2(mut log: ResMut<Log>) {
+            log.0.push(2);
+        }
+        fn log_3(mut log: ResMut<Log>) {
+            log.0.push(3);
+        }
+
+        fn fire_in_order(mut commands: Commands) {
+            // All three are queued from the same `apply_buffers` call, as siblings.
+            commands.run_system(log_1);
+            commands.run_system(log_2);
+            commands.run_system(log_3);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        world.run_system(fire_in_order);
+        // Siblings must run in the order they were requested, like any other queued `Command`.
+        assert_eq!(world.resource::<Log>().0, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn flat_batch_of_siblings_does_not_trip_recursion_limit() {
+        fn noop() {}
+
+        fn fire_many_siblings(mut commands: Commands) {
+            // More independent, non-recursive siblings than `MAX_RUN_RECURSION_DEPTH`, all queued from
+            // a single `apply_buffers` call. This is one level of nesting, not `MAX_RUN_RECURSION_DEPTH`
+            // of them, and must not be mistaken for runaway recursion.
+            for _ in 0..(MAX_RUN_RECURSION_DEPTH + 1) {
+                commands.run_system(noop);
+            }
+        }
+
+        let mut world = World::new();
+        world.run_system(fire_many_siblings);
+    }
 }